@@ -0,0 +1,45 @@
+use pyo3::{PyErr, Python};
+use thiserror::Error;
+use tonic::Status;
+use uuid::Uuid;
+
+/// Errors that can occur while servicing a single gRPC call against a
+/// [`crate::exporter::Session`]. These map to a [`Status`] so that a failure
+/// in one RPC (or one Python driver method) never tears down the server.
+#[derive(Debug, Error)]
+pub enum ExporterError {
+    #[error("invalid device uuid {raw:?}: {source}")]
+    InvalidUuid { raw: String, source: uuid::Error },
+    #[error("no device registered for uuid {0}")]
+    UnknownDevice(Uuid),
+    #[error("driver call failed: {0}")]
+    Python(#[from] PyErr),
+}
+
+impl From<ExporterError> for Status {
+    fn from(err: ExporterError) -> Self {
+        match err {
+            ExporterError::InvalidUuid { .. } => Status::invalid_argument(err.to_string()),
+            ExporterError::UnknownDevice(_) => Status::not_found(err.to_string()),
+            ExporterError::Python(err) => {
+                let traceback = Python::with_gil(|py| {
+                    let traceback = err
+                        .traceback(py)
+                        .and_then(|tb| tb.format().ok())
+                        .unwrap_or_default();
+                    format!("{err}\n{traceback}")
+                });
+                Status::internal(traceback)
+            }
+        }
+    }
+}
+
+/// Parses a device uuid coming off the wire, preserving the raw string in
+/// the error so a bad request is easy to diagnose from the client side.
+pub fn parse_uuid(raw: &str) -> Result<Uuid, ExporterError> {
+    Uuid::parse_str(raw).map_err(|source| ExporterError::InvalidUuid {
+        raw: raw.to_string(),
+        source,
+    })
+}
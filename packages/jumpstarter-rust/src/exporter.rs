@@ -1,18 +1,30 @@
 use pyo3::{
+    exceptions::PyRuntimeError,
     prelude::*,
     types::{IntoPyDict, PyDict, PyList, PyTuple},
 };
 use pyo3_async_runtimes::TaskLocals;
-use std::{collections::HashMap, pin::Pin};
-use tokio::{net::UnixListener, sync::mpsc};
+use std::{
+    collections::HashMap,
+    io,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tokio::{
+    io::{AsyncRead, AsyncWrite, ReadBuf},
+    net::UnixListener,
+    sync::mpsc,
+};
 use tokio_stream::{
     wrappers::{ReceiverStream, UnixListenerStream},
-    Stream,
+    Stream, StreamExt,
 };
+use tokio_vsock::{VsockAddr, VsockListener, VsockStream};
 use tonic::{transport::Server, Request, Response, Status, Streaming};
 use uuid::Uuid;
 
 use crate::{
+    error::{parse_uuid, ExporterError},
     google::protobuf::{self, value::Kind},
     jumpstarter::{
         self,
@@ -64,118 +76,116 @@ fn convert_struct_message<'py>(
     value: &protobuf::Struct,
     message: &Bound<'py, PyAny>,
     path: String,
-) {
-    message.call_method0("Clear").unwrap();
+) -> PyResult<()> {
+    message.call_method0("Clear")?;
     for (key, v) in &value.fields {
         convert_value_message(
-            &v,
-            &message.getattr("fields").unwrap().get_item(&key).unwrap(),
+            v,
+            &message.getattr("fields")?.get_item(key)?,
             format!("{0}.{1}", &path, &key),
-        );
+        )?;
     }
+    Ok(())
 }
 
 fn convert_list_message<'py>(
     value: &protobuf::ListValue,
     message: &Bound<'py, PyAny>,
     path: String,
-) {
-    message.call_method1("ClearField", ("values",)).unwrap();
+) -> PyResult<()> {
+    message.call_method1("ClearField", ("values",))?;
     for (index, item) in value.values.iter().enumerate() {
         convert_value_message(
             item,
-            &message
-                .getattr("values")
-                .unwrap()
-                .call_method0("add")
-                .unwrap(),
+            &message.getattr("values")?.call_method0("add")?,
             format!("{0}[{1}]", &path, &index),
-        );
+        )?;
     }
+    Ok(())
 }
 
-fn convert_value_message<'py>(value: &protobuf::Value, message: &Bound<'py, PyAny>, path: String) {
+fn convert_value_message<'py>(
+    value: &protobuf::Value,
+    message: &Bound<'py, PyAny>,
+    path: String,
+) -> PyResult<()> {
     match &value.kind {
         Some(Kind::NullValue(_)) => {
-            message.setattr("null_value", 0).unwrap();
+            message.setattr("null_value", 0)?;
         }
         Some(Kind::BoolValue(v)) => {
-            message.setattr("bool_value", v).unwrap();
+            message.setattr("bool_value", v)?;
         }
         Some(Kind::StringValue(v)) => {
-            message.setattr("string_value", v).unwrap();
+            message.setattr("string_value", v)?;
         }
         Some(Kind::NumberValue(v)) => {
-            message.setattr("number_value", v).unwrap();
+            message.setattr("number_value", v)?;
         }
         Some(Kind::StructValue(v)) => {
-            convert_struct_message(v, &message.getattr("struct_value").unwrap(), path);
+            convert_struct_message(v, &message.getattr("struct_value")?, path)?;
         }
         Some(Kind::ListValue(v)) => {
-            convert_list_message(v, &message.getattr("list_value").unwrap(), path);
+            convert_list_message(v, &message.getattr("list_value")?, path)?;
         }
         None => {}
     }
+    Ok(())
 }
 
 impl<'py> FromPyObject<'py> for protobuf::Value {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let kind = ob
-            .call_method1("WhichOneof", ("kind",))
-            .unwrap()
-            .extract::<Option<String>>()
-            .unwrap();
+            .call_method1("WhichOneof", ("kind",))?
+            .extract::<Option<String>>()?;
         match kind.as_deref() {
             None | Some("null_value") => Ok(Self {
                 kind: Some(Kind::NullValue(0)),
             }),
             Some("number_value") => Ok(Self {
                 kind: Some(Kind::NumberValue(
-                    ob.getattr("number_value")
-                        .unwrap()
-                        .extract::<f64>()
-                        .unwrap(),
+                    ob.getattr("number_value")?.extract::<f64>()?,
                 )),
             }),
             Some("bool_value") => Ok(Self {
                 kind: Some(Kind::BoolValue(
-                    ob.getattr("bool_value").unwrap().extract::<bool>().unwrap(),
+                    ob.getattr("bool_value")?.extract::<bool>()?,
                 )),
             }),
             Some("string_value") => Ok(Self {
                 kind: Some(Kind::StringValue(
-                    ob.getattr("string_value")
-                        .unwrap()
-                        .extract::<String>()
-                        .unwrap(),
+                    ob.getattr("string_value")?.extract::<String>()?,
                 )),
             }),
-            Some("list_value") => unimplemented!(),
+            Some("list_value") => {
+                let values = ob.getattr("list_value")?.getattr("values")?;
+                Ok(Self {
+                    kind: Some(Kind::ListValue(protobuf::ListValue {
+                        values: values
+                            .try_iter()?
+                            .map(|v| v?.extract::<protobuf::Value>())
+                            .collect::<PyResult<Vec<protobuf::Value>>>()?,
+                    })),
+                })
+            }
             Some("struct_value") => {
-                let dict = ob
-                    .getattr("struct_value")
-                    .unwrap()
-                    .getattr("fields")
-                    .unwrap();
+                let dict = ob.getattr("struct_value")?.getattr("fields")?;
                 Ok(Self {
                     kind: Some(Kind::StructValue(protobuf::Struct {
                         fields: dict
-                            .try_iter()
-                            .unwrap()
+                            .try_iter()?
                             .map(|l| {
-                                let key = l.unwrap().extract::<String>().unwrap();
-                                let value = dict
-                                    .get_item(&key)
-                                    .unwrap()
-                                    .extract::<protobuf::Value>()
-                                    .unwrap();
-                                (key, value)
+                                let key = l?.extract::<String>()?;
+                                let value = dict.get_item(&key)?.extract::<protobuf::Value>()?;
+                                Ok((key, value))
                             })
-                            .collect::<HashMap<String, protobuf::Value>>(),
+                            .collect::<PyResult<HashMap<String, protobuf::Value>>>()?,
                     })),
                 })
             }
-            Some(_) => unimplemented!(),
+            Some(other) => Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "unsupported google.protobuf.Value kind: {other}"
+            ))),
         }
     }
 }
@@ -183,17 +193,14 @@ impl<'py> FromPyObject<'py> for protobuf::Value {
 impl<'py> IntoPyObject<'py> for protobuf::Value {
     type Target = PyAny;
     type Output = Bound<'py, Self::Target>;
-    type Error = std::convert::Infallible;
+    type Error = PyErr;
 
     fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
         let value = py
-            .import("google.protobuf.struct_pb2")
-            .unwrap()
-            .getattr("Value")
-            .unwrap()
-            .call0()
-            .unwrap();
-        convert_value_message(&self, &value, "".to_string());
+            .import("google.protobuf.struct_pb2")?
+            .getattr("Value")?
+            .call0()?;
+        convert_value_message(&self, &value, "".to_string())?;
         Ok(value)
     }
 }
@@ -217,6 +224,46 @@ type StreamingDriverCallStream =
 type LogStreamStream = Pin<Box<dyn Stream<Item = Result<LogStreamResponse, Status>> + Send>>;
 type StreamStream = Pin<Box<dyn Stream<Item = Result<StreamResponse, Status>> + Send>>;
 
+/// Wraps a `tokio_vsock::VsockStream` so it can be handed to
+/// `Server::serve_with_incoming`, which requires connections to implement
+/// `tonic::transport::server::Connected` (a tonic-specific trait that
+/// `tokio-vsock` has no reason to implement itself).
+struct VsockConnection(VsockStream);
+
+impl tonic::transport::server::Connected for VsockConnection {
+    type ConnectInfo = ();
+
+    fn connect_info(&self) -> Self::ConnectInfo {}
+}
+
+impl AsyncRead for VsockConnection {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.0).poll_read(cx, buf)
+    }
+}
+
+impl AsyncWrite for VsockConnection {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.0).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.0).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.0).poll_shutdown(cx)
+    }
+}
+
 #[pymethods]
 impl Session {
     #[new]
@@ -251,7 +298,6 @@ impl Session {
                         .unwrap(),
                 );
                 let instance = device.get_item(3).unwrap();
-                dbg!(&uuid, device.get_item(2).unwrap());
                 mapping.insert(uuid, instance.unbind());
             }
             Self {
@@ -279,13 +325,15 @@ impl Session {
         let locals = pyo3_async_runtimes::TaskLocals::with_running_loop(py)?.copy_context(py)?;
         let session = self.clone();
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            let uds = UnixListenerStream::new(UnixListener::bind(path).unwrap());
+            let uds = UnixListenerStream::new(
+                UnixListener::bind(path).map_err(|e| PyRuntimeError::new_err(e.to_string()))?,
+            );
             Server::builder()
                 .add_service(
                     tonic_reflection::server::Builder::configure()
                         .register_encoded_file_descriptor_set(jumpstarter::v1::FILE_DESCRIPTOR_SET)
                         .build_v1alpha()
-                        .unwrap(),
+                        .map_err(|e| PyRuntimeError::new_err(e.to_string()))?,
                 )
                 .add_service(ExporterServiceServer::new(SessionExecutor {
                     session,
@@ -293,7 +341,7 @@ impl Session {
                 }))
                 .serve_with_incoming(uds)
                 .await
-                .unwrap();
+                .map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
             Ok(())
         })
     }
@@ -301,13 +349,15 @@ impl Session {
         let locals = pyo3_async_runtimes::TaskLocals::with_running_loop(py)?.copy_context(py)?;
         let session = self.clone();
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            let addr = "127.0.0.1:50051".parse().unwrap();
+            let addr = "127.0.0.1:50051"
+                .parse()
+                .map_err(|e: std::net::AddrParseError| PyRuntimeError::new_err(e.to_string()))?;
             Server::builder()
                 .add_service(
                     tonic_reflection::server::Builder::configure()
                         .register_encoded_file_descriptor_set(jumpstarter::v1::FILE_DESCRIPTOR_SET)
                         .build_v1alpha()
-                        .unwrap(),
+                        .map_err(|e| PyRuntimeError::new_err(e.to_string()))?,
                 )
                 .add_service(ExporterServiceServer::new(SessionExecutor {
                     session,
@@ -315,7 +365,49 @@ impl Session {
                 }))
                 .serve(addr)
                 .await
-                .unwrap();
+                .map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+            Ok(())
+        })
+    }
+    fn serve_vsock<'a>(&self, py: Python<'a>, cid: u32, port: u32) -> PyResult<Bound<'a, PyAny>> {
+        let locals = pyo3_async_runtimes::TaskLocals::with_running_loop(py)?.copy_context(py)?;
+        let session = self.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let listener = VsockListener::bind(VsockAddr::new(cid, port))
+                .map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+
+            // Unlike `UnixListener`, `tokio-vsock` has no incoming-stream
+            // wrapper of its own, so accept() is driven in a loop and fed
+            // through the same mpsc/ReceiverStream bridge used elsewhere in
+            // this file.
+            let (incoming_tx, incoming_rx) = mpsc::channel(16);
+            tokio::spawn(async move {
+                loop {
+                    let accepted = listener
+                        .accept()
+                        .await
+                        .map(|(stream, _addr)| VsockConnection(stream));
+                    if incoming_tx.send(accepted).await.is_err() {
+                        break;
+                    }
+                }
+            });
+            let incoming = ReceiverStream::new(incoming_rx);
+
+            Server::builder()
+                .add_service(
+                    tonic_reflection::server::Builder::configure()
+                        .register_encoded_file_descriptor_set(jumpstarter::v1::FILE_DESCRIPTOR_SET)
+                        .build_v1alpha()
+                        .map_err(|e| PyRuntimeError::new_err(e.to_string()))?,
+                )
+                .add_service(ExporterServiceServer::new(SessionExecutor {
+                    session,
+                    locals,
+                }))
+                .serve_with_incoming(incoming)
+                .await
+                .map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
             Ok(())
         })
     }
@@ -327,58 +419,68 @@ impl ExporterService for SessionExecutor {
     type LogStreamStream = LogStreamStream;
     async fn get_report(
         &self,
-        _request: Request<protobuf::Empty>,
+        request: Request<protobuf::Empty>,
     ) -> Result<Response<GetReportResponse>, Status> {
+        if let Some(client_version) = request
+            .metadata()
+            .get(crate::version::PROTOCOL_VERSION_METADATA_KEY)
+            .and_then(|value| value.to_str().ok())
+        {
+            if !crate::version::is_compatible(client_version) {
+                return Err(Status::failed_precondition(format!(
+                    "client protocol version {client_version} is incompatible with exporter protocol version {}",
+                    crate::version::PROTOCOL_VERSION
+                )));
+            }
+        }
+
         let mut reports = vec![];
-        Python::with_gil(|py| {
-            let devices = self
-                .session
-                .root_device
-                .call_method0(py, "enumerate")
-                .unwrap();
-            let devices: &Bound<'_, PyList> = devices.downcast_bound(py).unwrap();
+        Python::with_gil(|py| -> Result<(), ExporterError> {
+            let devices = self.session.root_device.call_method0(py, "enumerate")?;
+            let devices: &Bound<'_, PyList> = devices.downcast_bound(py).map_err(PyErr::from)?;
             for device in devices {
-                let t: &Bound<'_, PyTuple> = device.downcast().unwrap();
-                let parent = t.get_item(1).unwrap();
-                let name = t.get_item(2).unwrap();
-                let instance = t.get_item(3).unwrap();
-                let report = instance
-                    .call_method(
-                        "report",
-                        (),
-                        Some(
-                            &[("parent", parent), ("name", name)]
-                                .into_py_dict(py)
-                                .unwrap(),
-                        ),
-                    )
-                    .unwrap();
-                let uuid = report.getattr("uuid").unwrap().extract::<String>().unwrap();
-                let parent_uuid = report
-                    .getattr("parent_uuid")
-                    .unwrap()
-                    .extract::<Option<String>>()
-                    .unwrap();
-                let labels = report.getattr("labels").unwrap();
+                let t: &Bound<'_, PyTuple> = device.downcast().map_err(PyErr::from)?;
+                let parent = t.get_item(1)?;
+                let name = t.get_item(2)?;
+                let instance = t.get_item(3)?;
+                let report = instance.call_method(
+                    "report",
+                    (),
+                    Some(&[("parent", parent), ("name", name)].into_py_dict(py)?),
+                )?;
+                let uuid = report.getattr("uuid")?.extract::<String>()?;
+                let parent_uuid = report.getattr("parent_uuid")?.extract::<Option<String>>()?;
+                let labels = report.getattr("labels")?;
                 let labels = labels
-                    .try_iter()
-                    .unwrap()
-                    .map(|l| {
-                        let key = l.unwrap().extract::<String>().unwrap();
-                        let value = labels.get_item(&key).unwrap().extract::<String>().unwrap();
-                        (key, value)
+                    .try_iter()?
+                    .map(|l| -> PyResult<(String, String)> {
+                        let key = l?.extract::<String>()?;
+                        let value = labels.get_item(&key)?.extract::<String>()?;
+                        Ok((key, value))
                     })
-                    .collect::<HashMap<String, String>>();
+                    .collect::<PyResult<HashMap<String, String>>>()?;
                 reports.push(DriverInstanceReport {
                     uuid,
                     parent_uuid,
                     labels,
                 })
             }
-        });
+            Ok(())
+        })?;
+
+        let mut labels = self.session.labels.clone();
+        labels.insert(
+            crate::version::PROTOCOL_VERSION_LABEL.to_string(),
+            crate::version::PROTOCOL_VERSION.to_string(),
+        );
+        labels.insert(
+            crate::version::CAPABILITIES_LABEL.to_string(),
+            crate::version::CAPABILITIES.join(","),
+        );
+
         Ok(Response::new(GetReportResponse {
             uuid: self.session.uuid.to_string(),
-            labels: self.session.labels.clone(),
+            labels,
             reports,
         }))
     }
@@ -387,24 +489,23 @@ impl ExporterService for SessionExecutor {
         request: Request<DriverCallRequest>,
     ) -> Result<Response<DriverCallResponse>, Status> {
         let request = request.into_inner();
-        let uuid = Uuid::parse_str(&request.uuid).unwrap();
-        let fut = Python::with_gil(|py| {
-            pyo3_async_runtimes::into_future_with_locals(
+        let uuid = parse_uuid(&request.uuid)?;
+        let fut = Python::with_gil(|py| -> Result<_, ExporterError> {
+            let instance = self
+                .session
+                .mapping
+                .get(&uuid)
+                .ok_or(ExporterError::UnknownDevice(uuid))?;
+            Ok(pyo3_async_runtimes::into_future_with_locals(
                 &self.locals,
-                self.session
-                    .mapping
-                    .get(&uuid)
-                    .unwrap()
-                    .bind(py)
-                    .call_method1("DriverCall", (request, ""))
-                    .unwrap(),
-            )
-            .unwrap()
-        });
+                instance.bind(py).call_method1("DriverCall", (request, ""))?,
+            )?)
+        })?;
 
-        let res = fut.await.unwrap();
+        let res = fut.await.map_err(ExporterError::from)?;
 
-        let res = Python::with_gil(|py| res.extract::<DriverCallResponse>(py)).unwrap();
+        let res = Python::with_gil(|py| res.extract::<DriverCallResponse>(py))
+            .map_err(ExporterError::from)?;
 
         Ok(Response::new(res))
     }
@@ -413,40 +514,61 @@ impl ExporterService for SessionExecutor {
         request: Request<StreamingDriverCallRequest>,
     ) -> Result<Response<Self::StreamingDriverCallStream>, Status> {
         let request = request.into_inner();
-        let uuid = Uuid::parse_str(&request.uuid).unwrap();
+        let uuid = parse_uuid(&request.uuid)?;
 
         let (tx, rx) = mpsc::channel(128);
 
-        let generator = Python::with_gil(|py| {
-            self.session
+        let generator = Python::with_gil(|py| -> Result<_, ExporterError> {
+            let instance = self
+                .session
                 .mapping
                 .get(&uuid)
-                .unwrap()
+                .ok_or(ExporterError::UnknownDevice(uuid))?;
+            Ok(instance
                 .bind(py)
-                .call_method1("StreamingDriverCall", (request, ""))
-                .unwrap()
-                .unbind()
-        });
-
-        dbg!(&generator);
+                .call_method1("StreamingDriverCall", (request, ""))?
+                .unbind())
+        })?;
 
         let locals = Python::with_gil(|py| self.locals.clone_ref(py));
 
         tokio::spawn(async move {
-            while let Ok(v) = Python::with_gil(|py| {
-                pyo3_async_runtimes::into_future_with_locals(
-                    &locals,
-                    generator.bind(py).call_method0("__anext__").unwrap(),
-                )
-            }) {
-                if let Ok(v) = v.await {
-                    tx.send(Python::with_gil(|py| {
-                        Ok(v.extract::<StreamingDriverCallResponse>(py).unwrap())
-                    }))
-                    .await
-                    .unwrap();
-                } else {
-                    break;
+            loop {
+                let fut = Python::with_gil(|py| {
+                    pyo3_async_runtimes::into_future_with_locals(
+                        &locals,
+                        match generator.bind(py).call_method0("__anext__") {
+                            Ok(fut) => fut,
+                            Err(e) => {
+                                return Err(ExporterError::from(e));
+                            }
+                        },
+                    )
+                    .map_err(ExporterError::from)
+                });
+                let fut = match fut {
+                    Ok(fut) => fut,
+                    Err(_) => break,
+                };
+                match fut.await {
+                    Ok(v) => {
+                        let response = Python::with_gil(|py| {
+                            v.extract::<StreamingDriverCallResponse>(py)
+                                .map_err(ExporterError::from)
+                        });
+                        match response {
+                            Ok(response) => {
+                                if tx.send(Ok(response)).await.is_err() {
+                                    break;
+                                }
+                            }
+                            Err(e) => {
+                                let _ = tx.send(Err(Status::from(e))).await;
+                                break;
+                            }
+                        }
+                    }
+                    Err(_) => break,
                 }
             }
         });
@@ -455,9 +577,12 @@ impl ExporterService for SessionExecutor {
     }
     async fn log_stream(
         &self,
-        request: Request<protobuf::Empty>,
+        _request: Request<protobuf::Empty>,
     ) -> Result<Response<Self::LogStreamStream>, Status> {
-        unimplemented!()
+        // The subscription (and therefore log capture) is dropped once this
+        // stream is, i.e. as soon as the client disconnects.
+        let stream = crate::log::subscribe().filter_map(|record| record.ok().map(Ok));
+        Ok(Response::new(Box::pin(stream)))
     }
     async fn reset(
         &self,
@@ -475,6 +600,123 @@ impl RouterService for SessionExecutor {
         &self,
         request: Request<Streaming<StreamRequest>>,
     ) -> Result<Response<Self::StreamStream>, Status> {
-        todo!()
+        let mut inbound = request.into_inner();
+
+        let handshake = inbound
+            .message()
+            .await?
+            .ok_or_else(|| Status::invalid_argument("stream closed before handshake frame"))?;
+
+        let uuid = parse_uuid(&handshake.uuid)?;
+
+        let instance = Python::with_gil(|py| -> Result<_, ExporterError> {
+            Ok(self
+                .session
+                .mapping
+                .get(&uuid)
+                .ok_or(ExporterError::UnknownDevice(uuid))?
+                .clone_ref(py))
+        })?;
+
+        let locals = Python::with_gil(|py| self.locals.clone_ref(py));
+
+        // `Stream()` returns a duplex object, not a bare async generator: it
+        // is iterated (`__anext__`) by the outbound task to drain data
+        // flowing *out* of the driver, while writes flowing *in* go through
+        // its separate `write(payload)` coroutine, and the inbound task calls
+        // its `aclose` once the gRPC side stops sending. Driving `asend`
+        // and `__anext__` concurrently on one async generator is invalid in
+        // CPython ("asynchronous generator is already running"), so the two
+        // directions must not share the same underlying generator state.
+        let conn = Python::with_gil(|py| -> Result<_, ExporterError> {
+            Ok(instance.bind(py).call_method0("Stream")?.unbind())
+        })?;
+
+        let (tx, rx) = mpsc::channel(128);
+
+        // drain the python duplex object and emit StreamResponse frames
+        let outbound_conn = Python::with_gil(|py| conn.clone_ref(py));
+        let outbound_locals = Python::with_gil(|py| locals.clone_ref(py));
+        let outbound_tx = tx.clone();
+        tokio::spawn(async move {
+            loop {
+                let fut = Python::with_gil(|py| {
+                    pyo3_async_runtimes::into_future_with_locals(
+                        &outbound_locals,
+                        match outbound_conn.bind(py).call_method0("__anext__") {
+                            Ok(fut) => fut,
+                            Err(e) => return Err(ExporterError::from(e)),
+                        },
+                    )
+                    .map_err(ExporterError::from)
+                });
+                let fut = match fut {
+                    Ok(fut) => fut,
+                    Err(_) => break,
+                };
+                match fut.await {
+                    Ok(v) => {
+                        let response = Python::with_gil(|py| {
+                            v.extract::<StreamResponse>(py).map_err(ExporterError::from)
+                        });
+                        match response {
+                            Ok(response) => {
+                                if outbound_tx.send(Ok(response)).await.is_err() {
+                                    break;
+                                }
+                            }
+                            Err(e) => {
+                                let _ = outbound_tx.send(Err(Status::from(e))).await;
+                                break;
+                            }
+                        }
+                    }
+                    Err(_) => break,
+                }
+            }
+        });
+
+        // pump inbound StreamRequest payloads into the python side via the
+        // duplex object's `write` coroutine. The handshake frame only
+        // carries the routing uuid, so it is consumed above and not
+        // forwarded as a payload.
+        tokio::spawn(async move {
+            loop {
+                let frame = match inbound.message().await {
+                    Ok(Some(frame)) => frame,
+                    _ => break,
+                };
+                let fut = Python::with_gil(|py| {
+                    pyo3_async_runtimes::into_future_with_locals(
+                        &locals,
+                        match conn.bind(py).call_method1("write", (frame.payload,)) {
+                            Ok(fut) => fut,
+                            Err(_) => return None,
+                        },
+                    )
+                    .ok()
+                });
+                let Some(fut) = fut else { break };
+                if fut.await.is_err() {
+                    break;
+                }
+            }
+            // signal end-of-stream to the python side so it can tear down cleanly
+            let aclose = Python::with_gil(|py| {
+                pyo3_async_runtimes::into_future_with_locals(
+                    &locals,
+                    match conn.bind(py).call_method0("aclose") {
+                        Ok(fut) => fut,
+                        Err(_) => return None,
+                    },
+                )
+                .ok()
+            });
+            if let Some(aclose) = aclose {
+                let _ = aclose.await;
+            }
+        });
+
+        Ok(Response::new(Box::pin(ReceiverStream::new(rx))))
     }
 }
@@ -15,10 +15,15 @@ pub mod google {
     }
 }
 
+pub mod error;
 pub mod exporter;
+pub mod log;
+pub mod version;
 
 #[pymodule]
 fn jumpstarter_rust(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<exporter::Session>()?;
+    m.add_class::<log::LogHandler>()?;
+    log::install(m.py())?;
     Ok(())
 }
@@ -0,0 +1,87 @@
+use std::sync::OnceLock;
+
+use pyo3::prelude::*;
+use tokio::sync::broadcast;
+use tokio_stream::wrappers::BroadcastStream;
+
+use crate::jumpstarter::v1::LogStreamResponse;
+
+const CHANNEL_CAPACITY: usize = 1024;
+
+static LOG_CHANNEL: OnceLock<broadcast::Sender<LogStreamResponse>> = OnceLock::new();
+
+fn channel() -> &'static broadcast::Sender<LogStreamResponse> {
+    LOG_CHANNEL.get_or_init(|| broadcast::channel(CHANNEL_CAPACITY).0)
+}
+
+/// Subscribes a fresh receiver to exporter-side log records emitted by
+/// Python drivers (and the Rust side). The subscription is dropped along
+/// with the stream once the `log_stream` client disconnects, so capture has
+/// no cost when nobody is watching.
+pub fn subscribe() -> BroadcastStream<LogStreamResponse> {
+    BroadcastStream::new(channel().subscribe())
+}
+
+fn publish(uuid: String, level: String, message: String) {
+    // Sending is a no-op (the record is dropped) when there are no subscribers.
+    let _ = channel().send(LogStreamResponse {
+        uuid,
+        level,
+        message,
+    });
+}
+
+/// A duck-typed `logging.Handler` that forwards every record it receives
+/// onto the broadcast channel backing `ExporterService::log_stream`.
+/// Drivers that want their log lines tagged with a device uuid can set a
+/// `uuid` attribute on the `LogRecord` (e.g. via a `LoggerAdapter`).
+#[pyclass]
+pub struct LogHandler {
+    #[pyo3(get, set)]
+    level: i32,
+}
+
+#[pymethods]
+impl LogHandler {
+    #[new]
+    #[pyo3(signature = (level = 0))]
+    fn new(level: i32) -> Self {
+        Self { level }
+    }
+
+    /// Mirrors `logging.Handler.handle`: honors the configured level, then
+    /// emits every record that clears it.
+    fn handle(&self, record: &Bound<'_, PyAny>) -> PyResult<bool> {
+        let levelno = record.getattr("levelno")?.extract::<i32>()?;
+        if levelno < self.level {
+            return Ok(false);
+        }
+        self.emit(record)?;
+        Ok(true)
+    }
+
+    fn emit(&self, record: &Bound<'_, PyAny>) -> PyResult<()> {
+        if channel().receiver_count() == 0 {
+            return Ok(());
+        }
+        let message = record.call_method0("getMessage")?.extract::<String>()?;
+        let level = record.getattr("levelname")?.extract::<String>()?;
+        let uuid = record
+            .getattr("uuid")
+            .ok()
+            .and_then(|uuid| uuid.extract::<String>().ok())
+            .unwrap_or_default();
+        publish(uuid, level, message);
+        Ok(())
+    }
+}
+
+/// Registers a [`LogHandler`] on the root Python logger so driver/Python log
+/// records start flowing into the `log_stream` broadcast channel.
+pub fn install(py: Python<'_>) -> PyResult<()> {
+    let handler = Py::new(py, LogHandler::new(0))?;
+    py.import("logging")?
+        .call_method0("getLogger")?
+        .call_method1("addHandler", (handler,))?;
+    Ok(())
+}
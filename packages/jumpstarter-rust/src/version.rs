@@ -0,0 +1,45 @@
+//! Protocol-version negotiation for the `jumpstarter.v1` wire protocol.
+//!
+//! A client sends its protocol version in the `jumpstarter-protocol-version`
+//! gRPC metadata entry on `get_report`; the exporter advertises its own
+//! version and optional-capability set back through
+//! `GetReportResponse.labels` so both sides can detect a mismatch before it
+//! surfaces as an opaque panic deep in `driver_call`.
+
+/// gRPC metadata key a client sets to advertise the protocol version it
+/// speaks when calling `get_report`.
+pub const PROTOCOL_VERSION_METADATA_KEY: &str = "jumpstarter-protocol-version";
+
+/// Label key under which the exporter advertises its own protocol version
+/// in `GetReportResponse.labels`.
+pub const PROTOCOL_VERSION_LABEL: &str = "jumpstarter.dev/protocol-version";
+
+/// Label key under which the exporter advertises the optional capabilities
+/// it supports (e.g. `log_stream`, `router.stream`) in
+/// `GetReportResponse.labels`.
+pub const CAPABILITIES_LABEL: &str = "jumpstarter.dev/capabilities";
+
+/// Semantic version of the `jumpstarter.v1` wire protocol implemented by
+/// this build of the exporter.
+pub const PROTOCOL_VERSION: &str = "1.0.0";
+
+/// Optional capabilities this build of the exporter has implemented.
+pub const CAPABILITIES: &[&str] = &["log_stream", "router.stream"];
+
+/// Inclusive `(major, minor)` range of peer protocol versions this exporter
+/// can safely talk to.
+const MIN_SUPPORTED_VERSION: (u64, u64) = (1, 0);
+const MAX_SUPPORTED_VERSION: (u64, u64) = (1, u64::MAX);
+
+fn major_minor(version: &str) -> Option<(u64, u64)> {
+    let mut parts = version.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    Some((major, minor))
+}
+
+/// Checks whether `version`, as advertised by a peer, falls within the
+/// range of protocol versions this exporter can safely talk to.
+pub fn is_compatible(version: &str) -> bool {
+    major_minor(version).is_some_and(|v| v >= MIN_SUPPORTED_VERSION && v <= MAX_SUPPORTED_VERSION)
+}